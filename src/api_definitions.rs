@@ -0,0 +1,32 @@
+//! Structs representing the JSON shapes returned by the Replicate API.
+
+use serde::{Deserialize, Serialize};
+
+/// A single model belonging to a collection.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CollectionModel {
+    pub url: Option<String>,
+    pub owner: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+/// The response shape for `GET /collections/{slug}`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetCollectionModels {
+    pub name: String,
+    pub slug: String,
+    pub description: String,
+    #[serde(default)]
+    pub models: Option<Vec<CollectionModel>>,
+}
+
+/// The response shape for `GET /collections`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ListCollectionModels {
+    pub results: Vec<GetCollectionModels>,
+    #[serde(default)]
+    pub next: Option<String>,
+    #[serde(default)]
+    pub previous: Option<String>,
+}
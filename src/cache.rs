@@ -0,0 +1,73 @@
+//! Optional ETag-based response caching for collection reads.
+//!
+//! Enabled via [`Config::cache`](crate::config::Config::cache). When on, a successful response's
+//! `ETag` header and body are stored keyed by request URL; subsequent requests for the same URL
+//! send `If-None-Match: <etag>`, and a `304 Not Modified` reply returns the cached body instead
+//! of erroring on the non-2xx status.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A cached response body together with the `ETag` it was served with.
+#[derive(Clone, Debug)]
+pub struct CacheEntry {
+    pub etag: String,
+    pub body: String,
+}
+
+/// Pluggable store backing the ETag cache. Implement this to plug in your own storage (e.g.
+/// Redis, disk) instead of the in-memory default.
+pub trait CacheStore: Send + Sync {
+    /// Look up a previously cached response for `url`.
+    fn get(&self, url: &str) -> Option<CacheEntry>;
+
+    /// Store a response for `url`.
+    fn set(&self, url: &str, entry: CacheEntry);
+}
+
+impl std::fmt::Debug for dyn CacheStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("<dyn CacheStore>")
+    }
+}
+
+/// The default [`CacheStore`], backed by an in-memory map shared across clones of a `Config`.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryCache {
+    entries: Arc<Mutex<HashMap<String, CacheEntry>>>,
+}
+
+impl CacheStore for InMemoryCache {
+    fn get(&self, url: &str) -> Option<CacheEntry> {
+        self.entries.lock().unwrap().get(url).cloned()
+    }
+
+    fn set(&self, url: &str, entry: CacheEntry) {
+        self.entries.lock().unwrap().insert(url.to_string(), entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_cache_round_trip() {
+        let cache = InMemoryCache::default();
+        assert!(cache.get("https://api.replicate.com/v1/collections").is_none());
+
+        cache.set(
+            "https://api.replicate.com/v1/collections",
+            CacheEntry {
+                etag: String::from("\"abc123\""),
+                body: String::from("{}"),
+            },
+        );
+
+        let entry = cache
+            .get("https://api.replicate.com/v1/collections")
+            .unwrap();
+        assert_eq!(entry.etag, "\"abc123\"");
+        assert_eq!(entry.body, "{}");
+    }
+}
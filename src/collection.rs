@@ -17,9 +17,11 @@
 //! ```
 //!
 
+use reqwest::Method;
+
 use crate::{
     api_definitions::{GetCollectionModels, ListCollectionModels},
-    errors::ReplicateError, prediction::default_client,
+    errors::ReplicateError,
 };
 
 /// Used to interact with the [Collection Endpoints](https://replicate.com/docs/reference/http#collections.get).
@@ -51,25 +53,9 @@ impl Collection {
     /// # Ok::<(), replicate_rust::errors::ReplicateError>(())
     /// ```
     pub fn get(&self, collection_slug: &str) -> Result<GetCollectionModels, ReplicateError> {
-        let client = default_client();
-
-        let response = client
-            .get(format!(
-                "{}/collections/{}",
-                self.parent.base_url, collection_slug
-            ))
-            .header("Authorization", format!("Token {}", self.parent.auth))
-            .header("User-Agent", &self.parent.user_agent)
-            .send()?;
-
-        if !response.status().is_success() {
-            return Err(ReplicateError::ResponseError(response.text()?));
-        }
-
-        let response_string = response.text()?;
-        let response_struct: GetCollectionModels = serde_json::from_str(&response_string)?;
-
-        Ok(response_struct)
+        self.parent
+            .request(Method::GET, &format!("/collections/{}", collection_slug))
+            .send()
     }
 
     /// List all collections present in Replicate.
@@ -88,32 +74,212 @@ impl Collection {
     /// # Ok::<(), replicate_rust::errors::ReplicateError>(())
     /// ```
     pub fn list(&self) -> Result<ListCollectionModels, ReplicateError> {
-        let client = default_client();
-
-        let response = client
-            .get(format!("{}/collections", self.parent.base_url))
-            .header("Authorization", format!("Token {}", self.parent.auth))
-            .header("User-Agent", &self.parent.user_agent)
-            .send()?;
+        Self::get_page(
+            &self.parent,
+            &format!("{}/collections", self.parent.base_url),
+        )
+    }
 
-        if !response.status().is_success() {
-            return Err(ReplicateError::ResponseError(response.text()?));
+    /// List every collection present in Replicate, transparently following the `next` cursor
+    /// returned by the API until every page has been consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use replicate_rust::{Replicate, config::Config};
+    ///
+    /// let config = Config::default();
+    /// let replicate = Replicate::new(config);
+    ///
+    /// for collection in replicate.collections.list_all() {
+    ///     println!("Collection : {:?}", collection?);
+    /// }
+    ///
+    /// # Ok::<(), replicate_rust::errors::ReplicateError>(())
+    /// ```
+    pub fn list_all(&self) -> ListAllCollections {
+        ListAllCollections {
+            config: self.parent.clone(),
+            buffer: Vec::new().into_iter(),
+            next: Some(format!("{}/collections", self.parent.base_url)),
         }
+    }
+
+    /// Fetch a single page of collections from `url`, reusing the same auth/user-agent headers
+    /// as every other request. `url` may be a raw `next`/`previous` cursor returned by the API.
+    fn get_page(
+        config: &crate::config::Config,
+        url: &str,
+    ) -> Result<ListCollectionModels, ReplicateError> {
+        config.request_url(Method::GET, url).send()
+    }
+
+    /// Get a collection by slug, without blocking the current thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use replicate_rust::{Replicate, config::Config};
+    ///
+    /// # async fn run() -> Result<(), replicate_rust::errors::ReplicateError> {
+    /// let config = Config::default();
+    /// let replicate = Replicate::new(config);
+    ///
+    /// let collections = replicate.collections.get_async("audio-generation").await?;
+    /// println!("Collections : {:?}", collections);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_async(
+        &self,
+        collection_slug: &str,
+    ) -> Result<GetCollectionModels, ReplicateError> {
+        self.parent
+            .request(Method::GET, &format!("/collections/{}", collection_slug))
+            .send_async()
+            .await
+    }
+
+    /// List all collections present in Replicate, without blocking the current thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use replicate_rust::{Replicate, config::Config};
+    ///
+    /// # async fn run() -> Result<(), replicate_rust::errors::ReplicateError> {
+    /// let config = Config::default();
+    /// let replicate = Replicate::new(config);
+    ///
+    /// let collections = replicate.collections.list_async().await?;
+    /// println!("Collections : {:?}", collections);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_async(&self) -> Result<ListCollectionModels, ReplicateError> {
+        Self::get_page_async(
+            &self.parent,
+            &format!("{}/collections", self.parent.base_url),
+        )
+        .await
+    }
+
+    /// List every collection present in Replicate as a [`Stream`](futures::stream::Stream),
+    /// transparently following the `next` cursor returned by the API until every page has been
+    /// consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::stream::StreamExt;
+    /// use replicate_rust::{Replicate, config::Config};
+    ///
+    /// # async fn run() -> Result<(), replicate_rust::errors::ReplicateError> {
+    /// let config = Config::default();
+    /// let replicate = Replicate::new(config);
+    ///
+    /// let mut collections = Box::pin(replicate.collections.list_all_async());
+    /// while let Some(collection) = collections.next().await {
+    ///     println!("Collection : {:?}", collection?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all_async(
+        &self,
+    ) -> impl futures::stream::Stream<Item = Result<GetCollectionModels, ReplicateError>> {
+        let state = ListAllCollectionsState {
+            config: self.parent.clone(),
+            buffer: std::collections::VecDeque::new(),
+            next: Some(format!("{}/collections", self.parent.base_url)),
+        };
+
+        futures::stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    return Some((Ok(item), state));
+                }
+
+                let url = state.next.take()?;
+
+                match Collection::get_page_async(&state.config, &url).await {
+                    Ok(page) => {
+                        state.next = page.next;
+                        state.buffer.extend(page.results);
+                    }
+                    Err(err) => return Some((Err(err), state)),
+                }
+            }
+        })
+    }
 
-        let response_string = response.text()?;
-        let response_struct: ListCollectionModels = serde_json::from_str(&response_string)?;
+    /// Fetch a single page of collections from `url`, reusing the same auth/user-agent headers
+    /// as every other request. `url` may be a raw `next`/`previous` cursor returned by the API.
+    async fn get_page_async(
+        config: &crate::config::Config,
+        url: &str,
+    ) -> Result<ListCollectionModels, ReplicateError> {
+        config.request_url(Method::GET, url).send_async().await
+    }
+}
+
+/// Iterator returned by [`Collection::list_all`]. Lazily fetches the next page from the API's
+/// `next` cursor whenever the buffered results from the previous page are exhausted.
+pub struct ListAllCollections {
+    config: crate::config::Config,
+    buffer: std::vec::IntoIter<GetCollectionModels>,
+    next: Option<String>,
+}
 
-        Ok(response_struct)
+impl Iterator for ListAllCollections {
+    type Item = Result<GetCollectionModels, ReplicateError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.next() {
+                return Some(Ok(item));
+            }
+
+            let url = self.next.take()?;
+
+            match Collection::get_page(&self.config, &url) {
+                Ok(page) => {
+                    self.next = page.next;
+                    self.buffer = page.results.into_iter();
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
     }
 }
 
+/// State driving the [`Stream`](futures::stream::Stream) returned by
+/// [`Collection::list_all_async`].
+struct ListAllCollectionsState {
+    config: crate::config::Config,
+    buffer: std::collections::VecDeque<GetCollectionModels>,
+    next: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{config::Config, errors::ReplicateError, Replicate};
+    use crate::{
+        api_definitions::GetCollectionModels, config::Config, errors::ReplicateError, Replicate,
+    };
 
+    use httpmock::prelude::HttpMockRequest;
     use httpmock::{Method::GET, MockServer};
     use serde_json::json;
 
+    /// Matches a `/collections` request that isn't following a pagination cursor, so the
+    /// first-page mock doesn't also swallow requests meant for a later page.
+    fn is_first_page(req: &HttpMockRequest) -> bool {
+        !req
+            .query_params
+            .as_ref()
+            .is_some_and(|params| params.iter().any(|(key, _)| key == "cursor"))
+    }
+
     #[test]
     fn test_get() -> Result<(), ReplicateError> {
         let server = MockServer::start();
@@ -189,4 +355,243 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_get_async() -> Result<(), ReplicateError> {
+        let server = MockServer::start();
+
+        let get_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/collections/super-resolution");
+            then.status(200).json_body_obj(&json!( {
+                "name": "Super resolution",
+                "slug": "super-resolution",
+                "description": "Upscaling models that create high-quality images from low-quality images.",
+                "models": [],
+              }));
+        });
+
+        let config = Config {
+            auth: String::from("test"),
+            base_url: server.base_url(),
+            ..Config::default()
+        };
+        let replicate = Replicate::new(config);
+
+        let result = replicate.collections.get_async("super-resolution").await;
+
+        // Assert that the returned value is correct
+        assert_eq!(result?.name, "Super resolution");
+
+        // Ensure the mocks were called as expected
+        get_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_async() -> Result<(), ReplicateError> {
+        let server = MockServer::start();
+
+        let get_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/collections");
+            then.status(200).json_body_obj(&json!( {
+                "results": [
+                  {
+                    "name": "Super resolution",
+                    "slug": "super-resolution",
+                    "description": "Upscaling models that create high-quality images from low-quality images.",
+                  },
+                  {
+                    "name": "Image classification",
+                    "slug": "image-classification",
+                    "description": "Models that classify images.",
+                  },
+                ],
+                "next": None::<String>,
+                "previous": None::<String>,
+              }));
+        });
+
+        let config: Config = Config {
+            auth: String::from("test"),
+            base_url: server.base_url(),
+            ..Config::default()
+        };
+        let replicate = Replicate::new(config);
+
+        let result = replicate.collections.list_async().await?;
+
+        // Assert that the returned value is correct
+        assert_eq!(result.results.len(), 2);
+
+        // Ensure the mocks were called as expected
+        get_mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_all() -> Result<(), ReplicateError> {
+        let server = MockServer::start();
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/collections").matches(is_first_page);
+            then.status(200).json_body_obj(&json!( {
+                "results": [
+                  {
+                    "name": "Super resolution",
+                    "slug": "super-resolution",
+                    "description": "Upscaling models that create high-quality images from low-quality images.",
+                  },
+                ],
+                "next": format!("{}/collections?cursor=2", server.base_url()),
+                "previous": None::<String>,
+              }));
+        });
+
+        let second_page = server.mock(|when, then| {
+            when.method(GET).path("/collections").query_param("cursor", "2");
+            then.status(200).json_body_obj(&json!( {
+                "results": [
+                  {
+                    "name": "Image classification",
+                    "slug": "image-classification",
+                    "description": "Models that classify images.",
+                  },
+                ],
+                "next": None::<String>,
+                "previous": None::<String>,
+              }));
+        });
+
+        let config = Config {
+            auth: String::from("test"),
+            base_url: server.base_url(),
+            ..Config::default()
+        };
+        let replicate = Replicate::new(config);
+
+        let collections = replicate
+            .collections
+            .list_all()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Assert that every page was followed
+        assert_eq!(collections.len(), 2);
+        assert_eq!(collections[0].slug, "super-resolution");
+        assert_eq!(collections[1].slug, "image-classification");
+
+        // Ensure both pages were requested
+        first_page.assert();
+        second_page.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_all_async() -> Result<(), ReplicateError> {
+        use futures::stream::StreamExt;
+
+        let server = MockServer::start();
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/collections").matches(is_first_page);
+            then.status(200).json_body_obj(&json!( {
+                "results": [
+                  {
+                    "name": "Super resolution",
+                    "slug": "super-resolution",
+                    "description": "Upscaling models that create high-quality images from low-quality images.",
+                  },
+                ],
+                "next": format!("{}/collections?cursor=2", server.base_url()),
+                "previous": None::<String>,
+              }));
+        });
+
+        let second_page = server.mock(|when, then| {
+            when.method(GET).path("/collections").query_param("cursor", "2");
+            then.status(200).json_body_obj(&json!( {
+                "results": [
+                  {
+                    "name": "Image classification",
+                    "slug": "image-classification",
+                    "description": "Models that classify images.",
+                  },
+                ],
+                "next": None::<String>,
+                "previous": None::<String>,
+              }));
+        });
+
+        let config = Config {
+            auth: String::from("test"),
+            base_url: server.base_url(),
+            ..Config::default()
+        };
+        let replicate = Replicate::new(config);
+
+        let collections: Vec<GetCollectionModels> = replicate
+            .collections
+            .list_all_async()
+            .map(|result| result.unwrap())
+            .collect()
+            .await;
+
+        // Assert that every page was followed
+        assert_eq!(collections.len(), 2);
+        assert_eq!(collections[0].slug, "super-resolution");
+        assert_eq!(collections[1].slug, "image-classification");
+
+        // Ensure both pages were requested
+        first_page.assert();
+        second_page.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_revalidates_with_etag_when_cache_enabled() -> Result<(), ReplicateError> {
+        let server = MockServer::start();
+
+        let mut first = server.mock(|when, then| {
+            when.method(GET).path("/collections/super-resolution");
+            then.status(200).header("ETag", "\"abc123\"").json_body_obj(&json!( {
+                "name": "Super resolution",
+                "slug": "super-resolution",
+                "description": "Upscaling models that create high-quality images from low-quality images.",
+                "models": [],
+              }));
+        });
+
+        let config = Config {
+            auth: String::from("test"),
+            base_url: server.base_url(),
+            cache: true,
+            ..Config::default()
+        };
+        let replicate = Replicate::new(config);
+
+        let first_result = replicate.collections.get("super-resolution")?;
+        assert_eq!(first_result.name, "Super resolution");
+        first.assert();
+        first.delete();
+
+        // The second request should revalidate with `If-None-Match` and, on `304`, return the
+        // cached body instead of erroring on the non-2xx status.
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/collections/super-resolution")
+                .header("If-None-Match", "\"abc123\"");
+            then.status(304);
+        });
+
+        let second_result = replicate.collections.get("super-resolution")?;
+        assert_eq!(second_result.name, "Super resolution");
+        second.assert();
+
+        Ok(())
+    }
 }
@@ -0,0 +1,120 @@
+//! Holds the settings shared by every endpoint struct.
+
+use std::env;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::cache::{CacheStore, InMemoryCache};
+
+/// Controls how the shared request path retries transient failures.
+///
+/// Applied by the shared request path behind [`Config::request`]: on a `429` or `5xx` response
+/// to a `GET`, the `Retry-After` header is honored when present, otherwise the delay backs off
+/// exponentially from `base_delay`, for up to `max_retries` retries after the initial attempt.
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    /// Maximum number of retries before giving up and returning the error to the caller.
+    pub max_retries: u32,
+    /// The base delay used for exponential backoff when no `Retry-After` header is present.
+    pub base_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Holds the base url, auth token, and other settings used to make requests to the Replicate API.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The base url for the Replicate API, e.g. `https://api.replicate.com/v1`.
+    pub base_url: String,
+    /// The Replicate API token. Defaults to the `REPLICATE_API_TOKEN` environment variable.
+    pub auth: String,
+    /// The `User-Agent` header sent with every request.
+    pub user_agent: String,
+    /// Controls retry/backoff behavior for transient (`429`/`5xx`) failures.
+    pub retry: RetryConfig,
+    /// Whether to cache responses by `ETag` and revalidate with `If-None-Match`. Off by default.
+    pub cache: bool,
+    /// Shared "no requests before" timestamp set by a response's `Backoff` header. Cloning a
+    /// `Config` shares this state, so every endpoint struct built from the same config backs off
+    /// together.
+    pub(crate) backoff_until: Arc<Mutex<Option<Instant>>>,
+    /// The store backing [`Config::cache`]. Defaults to an in-memory map shared across clones of
+    /// this `Config`; swap in your own [`CacheStore`] to plug in different storage.
+    pub cache_store: Arc<dyn CacheStore>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            base_url: String::from("https://api.replicate.com/v1"),
+            auth: env::var("REPLICATE_API_TOKEN").unwrap_or_default(),
+            user_agent: format!("replicate-rust/{}", env!("CARGO_PKG_VERSION")),
+            retry: RetryConfig::default(),
+            cache: false,
+            backoff_until: Arc::new(Mutex::new(None)),
+            cache_store: Arc::new(InMemoryCache::default()),
+        }
+    }
+}
+
+impl Config {
+    /// Start building a request to `path` (joined onto [`Config::base_url`]). Call
+    /// [`TypedRequest::send`] or [`TypedRequest::send_async`] to run it and deserialize the
+    /// response, picking up auth headers, retry/backoff and ETag caching along the way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reqwest::Method;
+    /// use replicate_rust::{api_definitions::ListCollectionModels, config::Config};
+    ///
+    /// let config = Config::default();
+    /// let collections = config.request(Method::GET, "/collections").send::<ListCollectionModels>()?;
+    ///
+    /// # Ok::<(), replicate_rust::errors::ReplicateError>(())
+    /// ```
+    pub fn request(&self, method: reqwest::Method, path: &str) -> TypedRequest<'_> {
+        self.request_url(method, format!("{}{}", self.base_url, path))
+    }
+
+    /// Like [`Config::request`], but takes a fully-qualified URL instead of a path relative to
+    /// [`Config::base_url`]. Used to follow raw cursor URLs such as pagination's `next`.
+    pub fn request_url(&self, method: reqwest::Method, url: impl Into<String>) -> TypedRequest<'_> {
+        TypedRequest {
+            config: self,
+            method,
+            url: url.into(),
+        }
+    }
+}
+
+/// A single pending request built by [`Config::request`]. Holds the method and fully-qualified
+/// URL; the type to deserialize into is picked at [`send`](TypedRequest::send) time.
+pub struct TypedRequest<'a> {
+    config: &'a Config,
+    method: reqwest::Method,
+    url: String,
+}
+
+impl<'a> TypedRequest<'a> {
+    /// Issue the request and deserialize the response body into `T`.
+    pub fn send<T: serde::de::DeserializeOwned>(self) -> Result<T, crate::errors::ReplicateError> {
+        let body = crate::retry::send(self.method, self.config, &self.url)?;
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Async equivalent of [`TypedRequest::send`].
+    pub async fn send_async<T: serde::de::DeserializeOwned>(
+        self,
+    ) -> Result<T, crate::errors::ReplicateError> {
+        let body = crate::retry::send_async(self.method, self.config, &self.url).await?;
+        Ok(serde_json::from_str(&body)?)
+    }
+}
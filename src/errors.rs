@@ -0,0 +1,19 @@
+//! Error types returned by this crate.
+
+use thiserror::Error;
+
+/// The error type returned by all fallible operations in this crate.
+#[derive(Error, Debug)]
+pub enum ReplicateError {
+    /// The HTTP request itself failed (DNS, TLS, connection, timeout, etc).
+    #[error("request error: {0}")]
+    RequestError(#[from] reqwest::Error),
+
+    /// The response body could not be deserialized into the expected type.
+    #[error("failed to parse response: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    /// The server returned a non-success status code.
+    #[error("response error: {0}")]
+    ResponseError(String),
+}
@@ -0,0 +1,44 @@
+//! # replicate-rust
+//!
+//! A Rust client for the [Replicate](https://replicate.com) HTTP API.
+//!
+//! # Example
+//!
+//! ```
+//! use replicate_rust::{Replicate, config::Config};
+//!
+//! let config = Config::default();
+//! let replicate = Replicate::new(config);
+//!
+//! let collections = replicate.collections.get("audio-generation")?;
+//! println!("Collection : {:?}", collections);
+//!
+//! # Ok::<(), replicate_rust::errors::ReplicateError>(())
+//! ```
+
+pub mod api_definitions;
+pub mod cache;
+pub mod collection;
+pub mod config;
+pub mod errors;
+pub mod prediction;
+mod retry;
+
+use collection::Collection;
+
+/// The main entry point to the Replicate API. Holds a [`Config`](config::Config) and exposes
+/// the various endpoint structs, e.g. [`collections`](Collection).
+#[derive(Clone, Debug)]
+pub struct Replicate {
+    /// Used to interact with the Collection Endpoints.
+    pub collections: Collection,
+}
+
+impl Replicate {
+    /// Create a new Replicate struct.
+    pub fn new(config: config::Config) -> Self {
+        Self {
+            collections: Collection::new(config),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Helpers for building the HTTP clients shared by every endpoint struct.
+
+/// Build the blocking [`reqwest::blocking::Client`] used for synchronous requests.
+pub fn default_client() -> reqwest::blocking::Client {
+    reqwest::blocking::Client::new()
+}
+
+/// Build the async [`reqwest::Client`] used by the `_async` endpoint variants.
+pub fn default_async_client() -> reqwest::Client {
+    reqwest::Client::new()
+}
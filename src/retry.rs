@@ -0,0 +1,335 @@
+//! Shared retry/backoff handling for the request path used by every endpoint struct.
+//!
+//! On a `429` or transient `5xx` response, the `Retry-After` header is read (seconds or an
+//! HTTP-date) and honored before retrying, falling back to exponential backoff with jitter when
+//! the header is absent. A `Backoff` header, if present, proactively delays the *next* request
+//! via the shared [`Config::backoff_until`](crate::config::Config) timestamp.
+
+use std::time::{Duration, Instant, SystemTime};
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+use crate::cache::CacheEntry;
+use crate::config::Config;
+use crate::errors::ReplicateError;
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn backoff_header(headers: &HeaderMap) -> Option<u64> {
+    headers.get("Backoff")?.to_str().ok()?.parse::<u64>().ok()
+}
+
+fn etag_header(headers: &HeaderMap) -> Option<String> {
+    Some(headers.get(reqwest::header::ETAG)?.to_str().ok()?.to_string())
+}
+
+fn retry_after_header(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let at = httpdate::parse_http_date(value).ok()?;
+    at.duration_since(SystemTime::now()).ok()
+}
+
+/// Caps the exponent used for backoff doubling. `max_retries` is a user-settable `u32` with no
+/// documented upper bound, and `2u32.pow(attempt)` panics (debug) or wraps to zero (release)
+/// once `attempt` reaches 32, so the exponent is clamped well below that regardless of how high
+/// `max_retries` is set.
+const MAX_BACKOFF_EXPONENT: u32 = 20;
+
+fn retry_delay(headers: &HeaderMap, config: &Config, attempt: u32) -> Duration {
+    if let Some(retry_after) = retry_after_header(headers) {
+        return retry_after;
+    }
+
+    let backoff = config.retry.base_delay * 2u32.pow(attempt.min(MAX_BACKOFF_EXPONENT));
+    let jitter = Duration::from_millis(rand::random::<u64>() % 250);
+
+    backoff + jitter
+}
+
+fn record_backoff(config: &Config, headers: &HeaderMap) {
+    if let Some(secs) = backoff_header(headers) {
+        let mut backoff_until = config.backoff_until.lock().unwrap();
+        *backoff_until = Some(Instant::now() + Duration::from_secs(secs));
+    }
+}
+
+fn backoff_wait(config: &Config) -> Option<Duration> {
+    let until = *config.backoff_until.lock().unwrap();
+    until
+        .map(|until| until.saturating_duration_since(Instant::now()))
+        .filter(|remaining| !remaining.is_zero())
+}
+
+/// Perform `method` against `url` with the standard auth/user-agent headers. For `GET` requests
+/// only, this retries retryable statuses per `config.retry` and, when `config.cache` is enabled,
+/// revalidates via `If-None-Match`. Other methods are sent once and their result (success or
+/// error) is returned as-is: retrying a `5xx` or serving a cached body is only safe for an
+/// idempotent read, and nothing in this crate issues a non-`GET` request yet, but
+/// [`Config::request`](crate::config::Config::request) is meant to be reused by future
+/// (non-idempotent) endpoints. Returns the raw response body for the caller to deserialize.
+pub(crate) fn send(
+    method: reqwest::Method,
+    config: &Config,
+    url: &str,
+) -> Result<String, ReplicateError> {
+    let client = crate::prediction::default_client();
+    let is_get = method == reqwest::Method::GET;
+    let mut attempt = 0;
+    let cached = (is_get && config.cache)
+        .then(|| config.cache_store.get(url))
+        .flatten();
+
+    loop {
+        if let Some(wait) = backoff_wait(config) {
+            std::thread::sleep(wait);
+        }
+
+        let mut request = client
+            .request(method.clone(), url)
+            .header("Authorization", format!("Token {}", config.auth))
+            .header("User-Agent", &config.user_agent);
+
+        if let Some(cached) = &cached {
+            request = request.header("If-None-Match", cached.etag.clone());
+        }
+
+        let response = request.send()?;
+
+        record_backoff(config, response.headers());
+
+        let status = response.status();
+
+        if is_get && status == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                return Ok(cached.body);
+            }
+            return Err(ReplicateError::ResponseError(response.text()?));
+        }
+
+        if status.is_success() {
+            let etag = etag_header(response.headers());
+            let body = response.text()?;
+
+            if is_get && config.cache {
+                if let Some(etag) = etag {
+                    config.cache_store.set(url, CacheEntry { etag, body: body.clone() });
+                }
+            }
+
+            return Ok(body);
+        }
+
+        if !is_get || !is_retryable(status) || attempt >= config.retry.max_retries {
+            return Err(ReplicateError::ResponseError(response.text()?));
+        }
+
+        std::thread::sleep(retry_delay(response.headers(), config, attempt));
+        attempt += 1;
+    }
+}
+
+/// Async equivalent of [`send`].
+pub(crate) async fn send_async(
+    method: reqwest::Method,
+    config: &Config,
+    url: &str,
+) -> Result<String, ReplicateError> {
+    let client = crate::prediction::default_async_client();
+    let is_get = method == reqwest::Method::GET;
+    let mut attempt = 0;
+    let cached = (is_get && config.cache)
+        .then(|| config.cache_store.get(url))
+        .flatten();
+
+    loop {
+        if let Some(wait) = backoff_wait(config) {
+            tokio::time::sleep(wait).await;
+        }
+
+        let mut request = client
+            .request(method.clone(), url)
+            .header("Authorization", format!("Token {}", config.auth))
+            .header("User-Agent", &config.user_agent);
+
+        if let Some(cached) = &cached {
+            request = request.header("If-None-Match", cached.etag.clone());
+        }
+
+        let response = request.send().await?;
+
+        record_backoff(config, response.headers());
+
+        let status = response.status();
+
+        if is_get && status == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                return Ok(cached.body);
+            }
+            return Err(ReplicateError::ResponseError(response.text().await?));
+        }
+
+        if status.is_success() {
+            let etag = etag_header(response.headers());
+            let body = response.text().await?;
+
+            if is_get && config.cache {
+                if let Some(etag) = etag {
+                    config.cache_store.set(url, CacheEntry { etag, body: body.clone() });
+                }
+            }
+
+            return Ok(body);
+        }
+
+        if !is_get || !is_retryable(status) || attempt >= config.retry.max_retries {
+            return Err(ReplicateError::ResponseError(response.text().await?));
+        }
+
+        tokio::time::sleep(retry_delay(response.headers(), config, attempt)).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::config::RetryConfig;
+    use httpmock::prelude::HttpMockRequest;
+    use httpmock::{Method::GET as MockGet, MockServer};
+    use reqwest::header::HeaderValue;
+    use serde_json::json;
+
+    // httpmock's `.matches()` only accepts a non-capturing `fn` pointer, so the "fail N times"
+    // counter has to live in a static rather than being captured by a closure.
+    static RETRY_TEST_ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+    fn fails_first_two_attempts(_req: &HttpMockRequest) -> bool {
+        RETRY_TEST_ATTEMPTS.fetch_add(1, Ordering::SeqCst) < 2
+    }
+
+    #[test]
+    fn test_send_retries_on_503_with_retry_after_then_succeeds() -> Result<(), ReplicateError> {
+        let server = MockServer::start();
+
+        // Fail the first two requests, then let the path fall through to the success mock.
+        let failing = server.mock(|when, then| {
+            when.method(MockGet)
+                .path("/collections")
+                .matches(fails_first_two_attempts);
+            then.status(503).header("Retry-After", "0");
+        });
+
+        let succeeding = server.mock(|when, then| {
+            when.method(MockGet).path("/collections");
+            then.status(200).json_body_obj(&json!({
+                "results": [],
+                "next": None::<String>,
+                "previous": None::<String>,
+            }));
+        });
+
+        let config = Config {
+            auth: String::from("test"),
+            base_url: server.base_url(),
+            retry: RetryConfig {
+                max_retries: 5,
+                base_delay: Duration::from_millis(1),
+            },
+            ..Config::default()
+        };
+
+        let body = send(
+            reqwest::Method::GET,
+            &config,
+            &format!("{}/collections", config.base_url),
+        )?;
+
+        assert!(body.contains("\"results\""));
+        failing.assert_hits(2);
+        succeeding.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_send_gives_up_after_max_retries() {
+        let server = MockServer::start();
+
+        let failing = server.mock(|when, then| {
+            when.method(MockGet).path("/collections");
+            then.status(503).header("Retry-After", "0");
+        });
+
+        let config = Config {
+            auth: String::from("test"),
+            base_url: server.base_url(),
+            retry: RetryConfig {
+                max_retries: 2,
+                base_delay: Duration::from_millis(1),
+            },
+            ..Config::default()
+        };
+
+        let result = send(
+            reqwest::Method::GET,
+            &config,
+            &format!("{}/collections", config.base_url),
+        );
+
+        assert!(matches!(result, Err(ReplicateError::ResponseError(_))));
+
+        // The initial attempt plus `max_retries` retries.
+        failing.assert_hits(3);
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(is_retryable(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!is_retryable(StatusCode::NOT_FOUND));
+        assert!(!is_retryable(StatusCode::OK));
+    }
+
+    #[test]
+    fn test_retry_after_header_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, HeaderValue::from_static("2"));
+
+        assert_eq!(retry_after_header(&headers), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_retry_after_header_missing() {
+        let headers = HeaderMap::new();
+        assert_eq!(retry_after_header(&headers), None);
+    }
+
+    #[test]
+    fn test_backoff_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Backoff", HeaderValue::from_static("5"));
+
+        assert_eq!(backoff_header(&headers), Some(5));
+    }
+
+    #[test]
+    fn test_retry_delay_falls_back_to_exponential_backoff() {
+        let config = Config::default();
+        let headers = HeaderMap::new();
+
+        let delay = retry_delay(&headers, &config, 0);
+
+        assert!(delay >= config.retry.base_delay);
+        assert!(delay < config.retry.base_delay * 2 + Duration::from_millis(250));
+    }
+}